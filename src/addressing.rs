@@ -0,0 +1,122 @@
+//! MQTT-style addressing and subscription matching over `PrprSignalId`.
+//! Renders a node's signals as hierarchical topics
+//! (`<nuid>/<device-type>/<signal-name-or-id>`) and matches them against
+//! subscription patterns using single-level `+` and multi-level `#`
+//! wildcards. This lets a server register one handler for e.g.
+//! `+/SensorTemperature/+` and receive every temperature reading without
+//! hard-coding node IDs.
+
+use crate::messages::{PrprDeviceType, PrprNodeUid, PrprSignalId};
+
+/// Builds the topic for a signal: `<nuid>/<device-type>/<signal-name-or-id>`.
+pub fn topic_for(nuid: PrprNodeUid, device_type: &PrprDeviceType, sid: &PrprSignalId) -> String {
+    let signal = match sid {
+        PrprSignalId::Id(id) => id.to_string(),
+        PrprSignalId::Name(name) => name.clone(),
+    };
+    format!("{nuid}/{device_type:?}/{signal}")
+}
+
+/// Returns true if `topic` matches `pattern`, where `pattern` may use
+/// MQTT-style `+` (matches exactly one level) and `#` (matches all
+/// remaining levels, only valid as the final segment).
+pub fn matches(pattern: &str, topic: &str) -> bool {
+    let mut pattern_levels = pattern.split('/');
+    let mut topic_levels = topic.split('/');
+
+    loop {
+        match (pattern_levels.next(), topic_levels.next()) {
+            (Some("#"), _) => return true,
+            (Some("+"), Some(_)) => continue,
+            (Some(p), Some(t)) if p == t => continue,
+            (Some(_), _) => return false,
+            (None, None) => return true,
+            (None, Some(_)) => return false,
+        }
+    }
+}
+
+/// A subscription pattern paired with its handler.
+struct Subscription<H> {
+    pattern: String,
+    handler: H,
+}
+
+/// Routes incoming signal topics to subscriber handlers by pattern match,
+/// so a server need not hard-code individual node/signal addresses.
+pub struct SignalRouter<H> {
+    subscriptions: Vec<Subscription<H>>,
+}
+
+impl<H> Default for SignalRouter<H> {
+    fn default() -> Self {
+        Self {
+            subscriptions: Vec::new(),
+        }
+    }
+}
+
+impl<H> SignalRouter<H> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `handler` for every topic matching `pattern`.
+    pub fn subscribe(&mut self, pattern: impl Into<String>, handler: H) {
+        self.subscriptions.push(Subscription {
+            pattern: pattern.into(),
+            handler,
+        });
+    }
+
+    /// Returns every handler whose pattern matches `topic`.
+    pub fn matching(&self, topic: &str) -> Vec<&H> {
+        self.subscriptions
+            .iter()
+            .filter(|sub| matches(&sub.pattern, topic))
+            .map(|sub| &sub.handler)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_match() {
+        assert!(matches("1/SensorTemperature/0", "1/SensorTemperature/0"));
+        assert!(!matches("1/SensorTemperature/0", "1/SensorTemperature/1"));
+        assert!(!matches("1/SensorTemperature/0", "1/SensorTemperature"));
+    }
+
+    #[test]
+    fn plus_matches_exactly_one_level() {
+        assert!(matches("+/SensorTemperature/+", "1/SensorTemperature/0"));
+        assert!(matches("+/SensorTemperature/+", "2/SensorTemperature/temp"));
+        // A single `+` must not cross a level boundary.
+        assert!(!matches("+/SensorTemperature/+", "1/SensorTemperature/0/extra"));
+        assert!(!matches("+/SensorTemperature/+", "1/SensorHumidity/0"));
+    }
+
+    #[test]
+    fn hash_matches_remaining_levels_including_zero() {
+        assert!(matches("1/#", "1/SensorTemperature/0"));
+        // `#` also matches when there are no remaining levels at all.
+        assert!(matches("1/#", "1"));
+        assert!(!matches("1/#", "2/SensorTemperature/0"));
+    }
+
+    #[test]
+    fn subscription_routes_by_pattern() {
+        let mut router = SignalRouter::new();
+        router.subscribe("+/SensorTemperature/+", "handler-a");
+        router.subscribe("1/#", "handler-b");
+        router.subscribe("2/SensorHumidity/0", "handler-c");
+
+        let matched = router.matching("1/SensorTemperature/0");
+        assert_eq!(matched.len(), 2);
+        assert!(matched.contains(&&"handler-a"));
+        assert!(matched.contains(&&"handler-b"));
+    }
+}
@@ -36,11 +36,12 @@ pub enum ProperMessage {
     RegisterAllowed(PrprRegisterAllowed) = 2, // Server -> Node
     RegisterDenied(PrprRegisterDenied) = 3,   // Server -> Node
     RequestDetails(PrprRequestDetails) = 4,   // Server -> Node
-    //ServerPush(PrprServerPush) = 5,         // Server -> Node
+    ServerPush(PrprServerPush) = 5,           // Server -> Node
     Register(PrprNodeRegister) = 100, // Node -> Server
     Details(PrprDetails) = 101,       // Node -> Server
     NodePush(PrprNodePush) = 110,     // Node -> Server
     Poll(PrprPoll) = 111,             // Node -> Server
+    Relay(PrprRelay) = 120,           // Node -> Node, forwarded through a repeater
 }
 
 /// Message for acknowledgement of previous message, with status.
@@ -94,6 +95,18 @@ pub struct PrprRequestDetails {
     pub nuid: u128, // Node unique identifier
 }
 
+/// Message for Server to command a Node, pushing setpoints for one or more
+/// writable signals. Delivered through the outbox/Poll mechanism like any
+/// other server-initiated message, and acknowledged the same way.
+/// Node applies the setpoints and reports the achieved value back via
+/// PrprNodePush, with a PrprStatus reflecting whether it was applied.
+#[derive(Serialize, Deserialize)]
+pub struct PrprServerPush {
+    pub nuid: u128,                            // Node unique identifier
+    pub setp: Vec<(PrprSignalId, PrprSignal)>, // Signal setpoints
+    pub iupd: Vec<(PrprSignalId, u32)>,        // Optional update-interval overrides
+}
+
 /// Message for Node to push measurement values to Server.
 #[derive(Serialize, Deserialize)]
 pub struct PrprNodePush {
@@ -109,6 +122,17 @@ pub struct PrprNodePush {
 #[derive(Serialize, Deserialize)]
 pub struct PrprPoll {}
 
+/// Message wrapping an opaque inner frame to be forwarded by a repeater node.
+/// Sent by the inner frame's original sender to its next-hop neighbor, and
+/// re-wrapped hop by hop until it reaches its destination. `ttl` is
+/// decremented on every forward and the frame is dropped at zero, to kill
+/// routing loops.
+#[derive(Serialize, Deserialize)]
+pub struct PrprRelay {
+    pub ttl: u8,          // Remaining hops allowed
+    pub inner: Vec<u8>,   // Opaque, serialized ProperFrame
+}
+
 /// Message for Node to provide detailed information to Server.
 /// Sent as a response to RequestDetails.
 #[derive(Serialize, Deserialize)]
@@ -132,6 +156,7 @@ pub struct PrprSignalConf {
     pub smin: String,         // Minimum value, serialized as string
     pub smax: String,         // Maximum value, serialized as string
     pub supd: u32,            // Expected update interval in seconds
+    pub swrt: bool,           // Writable, server may ServerPush a setpoint for this signal
 }
 
 #[derive(Serialize, Deserialize)]
@@ -170,7 +195,7 @@ pub type PrprStatus = u16;
 /// These device types have predefined signals, for ease of integration.
 /// Non-standard devices can use Custom device types.
 #[repr(u8)]
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum PrprDeviceType {
     // Common sensors
     SensorTemperature = 1, // Temperature sensor
@@ -208,7 +233,7 @@ pub enum PrprDeviceType {
 /// Serialized as ID for MessagePack.
 /// Serialized as Name for JSON.
 /// Name can not start with a number, so that's how to differentiate.
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum PrprSignalId {
     Id(u8),       // 0-255
     Name(String), // Not allowed to start with a number
@@ -0,0 +1,169 @@
+//! Server-side outbox keyed by `PrprNodeUid`, queuing messages for nodes
+//! that only receive data by polling. A node sends `Poll`, the server
+//! replies with its oldest pending message and sets `pnd` if more remain,
+//! and the node later acknowledges via the `ack` field of a following
+//! `ProperFrame`. An unacked message is handed back on every subsequent
+//! Poll until it is acked or its retry budget (`max_retries`) runs out.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+
+use crate::messages::{PrprMessageId, PrprNodeUid, ProperMessage};
+
+/// A message enqueued for a node, pending acknowledgement.
+struct PendingMessage {
+    mid: PrprMessageId,
+    msg: ProperMessage,
+    retries: u32,
+}
+
+/// Per-node outbox queue, plus retransmit bookkeeping.
+#[derive(Default)]
+struct NodeOutbox {
+    queue: VecDeque<PendingMessage>,
+    seen_mids: Vec<PrprMessageId>, // Mids of the last 32 received Polls, to dedup retransmitted Polls
+}
+
+/// Server-side outbox managing the reliability state machine for all nodes.
+/// A message is removed from the queue once its `mid` appears in a later
+/// frame's `ack` list, or once it exceeds `max_retries`.
+pub struct ServerOutbox {
+    nodes: HashMap<PrprNodeUid, NodeOutbox>,
+    max_retries: u32,
+}
+
+impl ServerOutbox {
+    /// Creates a new outbox. `max_retries` bounds how many times a message
+    /// is retransmitted before being dropped, so a dead node's queue
+    /// cannot grow unbounded.
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            nodes: HashMap::new(),
+            max_retries,
+        }
+    }
+
+    /// Enqueues `msg` for delivery to `uid` the next time it polls.
+    pub fn enqueue(&mut self, uid: PrprNodeUid, mid: PrprMessageId, msg: ProperMessage) {
+        self.nodes.entry(uid).or_default().queue.push_back(PendingMessage {
+            mid,
+            msg,
+            retries: 0,
+        });
+    }
+
+    /// Handles a `Poll` from `uid`, returning the oldest pending message and
+    /// whether further messages remain (for the outgoing frame's `pnd`
+    /// field). Messages whose retry budget is exhausted are dropped first,
+    /// so the next queued message is returned in their place; `None` means
+    /// no message is left for this node.
+    /// Dedups against `incoming_mid` so a retransmitted Poll frame is a
+    /// pure no-op: it neither advances the retry counter nor re-evaluates
+    /// expiry, so it can never expire a message that a fresh poll wouldn't
+    /// have. A message is always delivered at least once before it can be
+    /// dropped.
+    pub fn poll(
+        &mut self,
+        uid: PrprNodeUid,
+        incoming_mid: PrprMessageId,
+    ) -> Option<(&ProperMessage, bool)> {
+        let outbox = self.nodes.get_mut(&uid)?;
+
+        let is_new_poll = !outbox.seen_mids.contains(&incoming_mid);
+        if is_new_poll {
+            outbox.seen_mids.push(incoming_mid);
+            if outbox.seen_mids.len() > 32 {
+                outbox.seen_mids.remove(0);
+            }
+
+            self.expire(uid);
+
+            let outbox = self.nodes.get_mut(&uid)?;
+            if let Some(front) = outbox.queue.front_mut() {
+                front.retries += 1;
+            }
+        }
+
+        let outbox = self.nodes.get(&uid)?;
+        let front = outbox.queue.front()?;
+        let pending = outbox.queue.len() > 1;
+        Some((&front.msg, pending))
+    }
+
+    /// Removes acknowledged messages for `uid`. Call with the `ack` list
+    /// from any frame the node sends.
+    pub fn ack(&mut self, uid: PrprNodeUid, acked: &[PrprMessageId]) {
+        if let Some(outbox) = self.nodes.get_mut(&uid) {
+            outbox.queue.retain(|pending| !acked.contains(&pending.mid));
+        }
+    }
+
+    /// Drops messages that have exceeded `max_retries` for `uid`.
+    fn expire(&mut self, uid: PrprNodeUid) {
+        if let Some(outbox) = self.nodes.get_mut(&uid) {
+            outbox
+                .queue
+                .retain(|pending| pending.retries <= self.max_retries);
+        }
+    }
+
+    /// True if `uid` has no pending messages.
+    pub fn is_empty(&self, uid: PrprNodeUid) -> bool {
+        self.nodes
+            .get(&uid)
+            .map(|outbox| outbox.queue.is_empty())
+            .unwrap_or(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::AckStatus;
+
+    fn dummy_message() -> ProperMessage {
+        ProperMessage::AckStatus(AckStatus { rmid: 0, code: 0 })
+    }
+
+    #[test]
+    fn zero_retries_delivers_once_then_expires() {
+        let mut outbox = ServerOutbox::new(0);
+        outbox.enqueue(1, 100, dummy_message());
+
+        assert!(outbox.poll(1, 1).is_some()); // original delivery
+        assert!(outbox.poll(1, 2).is_none()); // retry budget exhausted
+    }
+
+    #[test]
+    fn retransmits_until_max_retries_then_expires() {
+        let mut outbox = ServerOutbox::new(2);
+        outbox.enqueue(1, 100, dummy_message());
+
+        assert!(outbox.poll(1, 1).is_some()); // original delivery
+        assert!(outbox.poll(1, 2).is_some()); // retry 1
+        assert!(outbox.poll(1, 3).is_some()); // retry 2
+        assert!(outbox.poll(1, 4).is_none()); // retry budget exhausted
+    }
+
+    #[test]
+    fn duplicate_poll_mid_does_not_advance_retry_count() {
+        let mut outbox = ServerOutbox::new(0);
+        outbox.enqueue(1, 100, dummy_message());
+
+        assert!(outbox.poll(1, 1).is_some());
+        // Same mid as a prior poll, i.e. the node retransmitted its Poll
+        // frame; must not count as a second delivery attempt.
+        assert!(outbox.poll(1, 1).is_some());
+    }
+
+    #[test]
+    fn ack_removes_message() {
+        let mut outbox = ServerOutbox::new(5);
+        outbox.enqueue(1, 100, dummy_message());
+        outbox.poll(1, 1);
+
+        outbox.ack(1, &[100]);
+
+        assert!(outbox.is_empty(1));
+    }
+}
@@ -0,0 +1,100 @@
+//! Multi-hop relay routing for repeater nodes.
+//! Nodes are assumed to talk directly to the server (uid 0), but a battery
+//! or low-power node may only be reachable through one or more repeaters.
+//! A `PrprRelay` frame carries an opaque inner frame one hop at a time; the
+//! `RoutingTable` records, per destination, which neighbor to forward
+//! through next, learning the reverse path from the frames it forwards.
+//! Forwarding decrements the frame's TTL and drops it at zero, so a
+//! misconfigured or cyclic topology cannot loop frames forever.
+
+use std::collections::HashMap;
+
+use crate::messages::PrprNodeUid;
+
+/// Next-hop entry for a destination node.
+struct Route {
+    next_hop: PrprNodeUid,
+    metric: u32, // Hop count to destination, lower is better
+}
+
+/// Tracks the best known next hop toward every destination this node or
+/// server has seen traffic for.
+#[derive(Default)]
+pub struct RoutingTable {
+    routes: HashMap<PrprNodeUid, Route>,
+}
+
+impl RoutingTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records that `src` is reachable via `via` at `metric` hops, learning
+    /// the reverse path as a side effect of forwarding its frame. Only
+    /// replaces an existing route if the new one is cheaper.
+    pub fn learn(&mut self, src: PrprNodeUid, via: PrprNodeUid, metric: u32) {
+        let better = self
+            .routes
+            .get(&src)
+            .map(|route| metric < route.metric)
+            .unwrap_or(true);
+        if better {
+            self.routes.insert(
+                src,
+                Route {
+                    next_hop: via,
+                    metric,
+                },
+            );
+        }
+    }
+
+    /// Returns the next-hop neighbor to forward a frame bound for `dst`,
+    /// or `None` if no route is known.
+    pub fn next_hop(&self, dst: PrprNodeUid) -> Option<PrprNodeUid> {
+        self.routes.get(&dst).map(|route| route.next_hop)
+    }
+
+    /// Removes the route to `dst`; `next_hop` returns `None` for it until a
+    /// route is learned again.
+    pub fn forget(&mut self, dst: PrprNodeUid) {
+        self.routes.remove(&dst);
+    }
+}
+
+/// Decrements `ttl` for a frame about to be forwarded, returning `None`
+/// once it reaches zero so the caller drops the frame instead of
+/// relaying it further.
+pub fn decrement_ttl(ttl: u8) -> Option<u8> {
+    ttl.checked_sub(1).filter(|remaining| *remaining > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn costlier_route_does_not_displace_a_cheaper_one() {
+        let mut table = RoutingTable::new();
+        table.learn(10, 20, 1);
+        table.learn(10, 30, 2);
+
+        assert_eq!(table.next_hop(10), Some(20));
+    }
+
+    #[test]
+    fn cheaper_route_displaces_a_costlier_one() {
+        let mut table = RoutingTable::new();
+        table.learn(10, 30, 2);
+        table.learn(10, 20, 1);
+
+        assert_eq!(table.next_hop(10), Some(20));
+    }
+
+    #[test]
+    fn decrement_ttl_drops_at_zero_and_one() {
+        assert_eq!(decrement_ttl(0), None);
+        assert_eq!(decrement_ttl(1), None);
+        assert_eq!(decrement_ttl(2), Some(1));
+    }
+}
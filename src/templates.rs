@@ -0,0 +1,188 @@
+//! Standard signal templates for Proper Home Automation device types.
+//! This module gives the fixed `PrprDeviceType` variants their predefined
+//! signal set: the ID, type, unit and default update interval a server can
+//! expect a factory-standard node to report for that device type.
+//! Custom device types have no template and return an empty slice; their
+//! signal set is whatever the node reports in `PrprDetails.sign`.
+
+use crate::messages::{PrprDeviceType, PrprSignalId, PrprSignalType};
+
+/// Canonical signal expected from a standard device type.
+/// Servers can validate an incoming `PrprDetails.sign` against this template
+/// and auto-provision integrations for factory-standard nodes.
+pub struct StandardSignalSpec {
+    pub sid: PrprSignalId,   // Expected signal ID
+    pub styp: PrprSignalType, // Expected signal type
+    pub unit: &'static str,  // Unit of measurement, for display purposes
+    pub supd: u32,           // Sane default update interval in seconds
+}
+
+impl PrprDeviceType {
+    /// Returns the canonical signal set for this device type, or an empty
+    /// slice for `Custom*` types and device types with no fixed template.
+    pub fn standard_signals(&self) -> &'static [StandardSignalSpec] {
+        match self {
+            PrprDeviceType::SensorTemperature => &[StandardSignalSpec {
+                sid: PrprSignalId::Id(0),
+                styp: PrprSignalType::Temperature,
+                unit: "°C",
+                supd: 60,
+            }],
+            PrprDeviceType::SensorHumidity => &[StandardSignalSpec {
+                sid: PrprSignalId::Id(0),
+                styp: PrprSignalType::Humidity,
+                unit: "%rh",
+                supd: 60,
+            }],
+            PrprDeviceType::SensorPressure => &[StandardSignalSpec {
+                sid: PrprSignalId::Id(0),
+                styp: PrprSignalType::Pressure,
+                unit: "Pa",
+                supd: 60,
+            }],
+            PrprDeviceType::SensorLight => &[StandardSignalSpec {
+                sid: PrprSignalId::Id(0),
+                styp: PrprSignalType::Light,
+                unit: "lx",
+                supd: 60,
+            }],
+            PrprDeviceType::SensorMotion => &[StandardSignalSpec {
+                sid: PrprSignalId::Id(0),
+                styp: PrprSignalType::Motion,
+                unit: "",
+                supd: 0, // Event driven, no periodic update expected
+            }],
+            PrprDeviceType::SensorVibration => &[StandardSignalSpec {
+                sid: PrprSignalId::Id(0),
+                styp: PrprSignalType::Motion,
+                unit: "",
+                supd: 0,
+            }],
+            PrprDeviceType::SensorOccupancy => &[StandardSignalSpec {
+                sid: PrprSignalId::Id(0),
+                styp: PrprSignalType::OnOff,
+                unit: "",
+                supd: 0,
+            }],
+            PrprDeviceType::SensorSmoke => &[StandardSignalSpec {
+                sid: PrprSignalId::Id(0),
+                styp: PrprSignalType::OnOff,
+                unit: "",
+                supd: 0,
+            }],
+            PrprDeviceType::SensorOnOff => &[StandardSignalSpec {
+                sid: PrprSignalId::Id(0),
+                styp: PrprSignalType::OnOff,
+                unit: "",
+                supd: 300,
+            }],
+            PrprDeviceType::SensorAnalog => &[StandardSignalSpec {
+                sid: PrprSignalId::Id(0),
+                styp: PrprSignalType::State,
+                unit: "",
+                supd: 60,
+            }],
+            PrprDeviceType::ActuatorRelay => &[StandardSignalSpec {
+                sid: PrprSignalId::Id(0),
+                styp: PrprSignalType::OnOff,
+                unit: "",
+                supd: 300,
+            }],
+            PrprDeviceType::ActuatorDimmer => &[
+                StandardSignalSpec {
+                    sid: PrprSignalId::Id(0),
+                    styp: PrprSignalType::OnOff,
+                    unit: "",
+                    supd: 300,
+                },
+                StandardSignalSpec {
+                    sid: PrprSignalId::Id(1),
+                    styp: PrprSignalType::State,
+                    unit: "%",
+                    supd: 300,
+                },
+            ],
+            PrprDeviceType::ActuatorShade => &[StandardSignalSpec {
+                sid: PrprSignalId::Id(0),
+                styp: PrprSignalType::State,
+                unit: "%",
+                supd: 300,
+            }],
+            PrprDeviceType::ActuatorValve => &[StandardSignalSpec {
+                sid: PrprSignalId::Id(0),
+                styp: PrprSignalType::OnOff,
+                unit: "",
+                supd: 300,
+            }],
+            PrprDeviceType::ActuatorLock => &[StandardSignalSpec {
+                sid: PrprSignalId::Id(0),
+                styp: PrprSignalType::OnOff,
+                unit: "",
+                supd: 0,
+            }],
+            PrprDeviceType::ActuatorFan => &[
+                StandardSignalSpec {
+                    sid: PrprSignalId::Id(0),
+                    styp: PrprSignalType::OnOff,
+                    unit: "",
+                    supd: 300,
+                },
+                StandardSignalSpec {
+                    sid: PrprSignalId::Id(1),
+                    styp: PrprSignalType::State,
+                    unit: "%",
+                    supd: 300,
+                },
+            ],
+            PrprDeviceType::ActuatorHeater => &[StandardSignalSpec {
+                sid: PrprSignalId::Id(0),
+                styp: PrprSignalType::Temperature,
+                unit: "°C",
+                supd: 300,
+            }],
+            PrprDeviceType::ActuatorLight => &[StandardSignalSpec {
+                sid: PrprSignalId::Id(0),
+                styp: PrprSignalType::OnOff,
+                unit: "",
+                supd: 300,
+            }],
+            PrprDeviceType::ActuatorOnOff => &[StandardSignalSpec {
+                sid: PrprSignalId::Id(0),
+                styp: PrprSignalType::OnOff,
+                unit: "",
+                supd: 300,
+            }],
+            PrprDeviceType::ActuatorAnalog => &[StandardSignalSpec {
+                sid: PrprSignalId::Id(0),
+                styp: PrprSignalType::State,
+                unit: "",
+                supd: 300,
+            }],
+            PrprDeviceType::CustomSensor
+            | PrprDeviceType::CustomActuator
+            | PrprDeviceType::CustomCombined => &[],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn actuator_dimmer_yields_onoff_and_percentage() {
+        let specs = PrprDeviceType::ActuatorDimmer.standard_signals();
+
+        assert_eq!(specs.len(), 2);
+        assert!(matches!(specs[0].styp, PrprSignalType::OnOff));
+        assert!(matches!(specs[1].styp, PrprSignalType::State));
+        assert_eq!(specs[1].unit, "%");
+    }
+
+    #[test]
+    fn custom_device_types_have_no_template() {
+        assert!(PrprDeviceType::CustomSensor.standard_signals().is_empty());
+        assert!(PrprDeviceType::CustomActuator.standard_signals().is_empty());
+        assert!(PrprDeviceType::CustomCombined.standard_signals().is_empty());
+    }
+}